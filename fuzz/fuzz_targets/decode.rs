@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    // decode/decode_check must never panic on arbitrary input, and any
+    // successful decode must re-encode to a canonical form.
+    if let Ok(bytes) = b58uuid::decode(input) {
+        let reencoded = b58uuid::encode(&bytes);
+        assert_eq!(reencoded.len(), 22);
+        assert_eq!(b58uuid::decode(&reencoded), Ok(bytes));
+    }
+
+    if let Ok(payload) = b58uuid::decode_check(input) {
+        let reencoded = b58uuid::encode_check(&payload);
+        assert_eq!(b58uuid::decode_check(&reencoded), Ok(payload));
+    }
+});
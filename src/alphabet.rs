@@ -0,0 +1,173 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::error::B58UUIDError;
+
+/// A Base58 alphabet: 58 unique ASCII symbols mapped to digits 0..58, plus
+/// the reverse lookup table built from them.
+///
+/// The symbol at index 0 (e.g. Bitcoin's `'1'`) is used both to represent
+/// leading zero bytes and to pad fixed-width output.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Base58Alphabet {
+    pub(crate) chars: [u8; 58],
+    pub(crate) reverse: [u8; 256],
+}
+
+impl Base58Alphabet {
+    /// Builds an alphabet from a 58-character string, validating that the
+    /// string is ASCII and every character is unique.
+    ///
+    /// # Example
+    /// ```
+    /// use b58uuid::Base58Alphabet;
+    ///
+    /// let custom = Base58Alphabet::new(
+    ///     "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz"
+    /// ).unwrap();
+    /// ```
+    pub fn new(alphabet: &str) -> Result<Self, B58UUIDError> {
+        let bytes = alphabet.as_bytes();
+        if bytes.len() != 58 {
+            return Err(B58UUIDError::InvalidLength {
+                expected: 58,
+                got: bytes.len(),
+            });
+        }
+        if !alphabet.is_ascii() {
+            return Err(B58UUIDError::InvalidBase58(
+                "alphabet must be ASCII".to_string(),
+            ));
+        }
+
+        let mut seen = [false; 256];
+        for &b in bytes {
+            if seen[b as usize] {
+                return Err(B58UUIDError::InvalidBase58(format!(
+                    "duplicate character in alphabet: {}",
+                    b as char
+                )));
+            }
+            seen[b as usize] = true;
+        }
+
+        let mut chars = [0u8; 58];
+        chars.copy_from_slice(bytes);
+        Ok(Self::from_chars(chars))
+    }
+
+    /// Builds the reverse lookup table for a set of 58 already-validated
+    /// symbols. Used at compile time to build the built-in alphabets.
+    const fn from_chars(chars: [u8; 58]) -> Self {
+        let mut reverse = [255u8; 256];
+        let mut i = 0usize;
+        while i < 58 {
+            reverse[chars[i] as usize] = i as u8;
+            i += 1;
+        }
+        Self { chars, reverse }
+    }
+
+    /// The symbol used for a zero digit: leading zero bytes and fixed-width
+    /// padding are both represented by this character.
+    pub(crate) fn zero_char(&self) -> u8 {
+        self.chars[0]
+    }
+
+    /// The Bitcoin Base58 alphabet (omits `0`, `O`, `I`, `l`).
+    pub const BITCOIN: Base58Alphabet =
+        Base58Alphabet::from_chars(*b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+
+    /// The Ripple Base58 alphabet.
+    pub const RIPPLE: Base58Alphabet =
+        Base58Alphabet::from_chars(*b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz");
+
+    /// The Flickr Base58 alphabet (lowercase before uppercase).
+    pub const FLICKR: Base58Alphabet =
+        Base58Alphabet::from_chars(*b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ");
+}
+
+/// Encodes an arbitrary-length byte slice to a Base58 string using the
+/// given alphabet. See [`crate::encode_bytes`] for the algorithm.
+pub(crate) fn encode_bytes_with(alphabet: &Base58Alphabet, data: &[u8]) -> String {
+    let mut leading_zeros = 0;
+    for &byte in data {
+        if byte == 0 {
+            leading_zeros += 1;
+        } else {
+            break;
+        }
+    }
+
+    let mut buffer = data.to_vec();
+    let mut digits = Vec::new();
+
+    while buffer.iter().any(|&b| b != 0) {
+        let mut remainder: u32 = 0;
+        for byte in buffer.iter_mut() {
+            let acc = (remainder << 8) | *byte as u32;
+            *byte = (acc / 58) as u8;
+            remainder = acc % 58;
+        }
+        digits.push(alphabet.chars[remainder as usize]);
+    }
+
+    let mut result = vec![alphabet.zero_char(); leading_zeros];
+    result.extend(digits.into_iter().rev());
+
+    String::from_utf8(result).expect("Base58 encoding should always be valid UTF-8")
+}
+
+/// Decodes a Base58 string to its arbitrary-length byte representation
+/// using the given alphabet. See [`crate::decode_bytes`] for the algorithm.
+pub(crate) fn decode_bytes_with(
+    alphabet: &Base58Alphabet,
+    b58: &str,
+) -> Result<Vec<u8>, B58UUIDError> {
+    // Unlike `Engine::decode`, an empty string is a valid (zero-length) input here.
+    let zero_char = alphabet.zero_char() as char;
+    let mut leading_zeros = 0;
+    for ch in b58.chars() {
+        if ch == zero_char {
+            leading_zeros += 1;
+        } else {
+            break;
+        }
+    }
+
+    let mut buffer: Vec<u8> = Vec::new();
+    for (i, ch) in b58.chars().enumerate().skip(leading_zeros) {
+        if !ch.is_ascii() {
+            return Err(B58UUIDError::InvalidBase58(format!(
+                "Invalid character at position {}: {}",
+                i, ch
+            )));
+        }
+
+        let digit = alphabet.reverse[ch as usize];
+        if digit == 255 {
+            return Err(B58UUIDError::InvalidBase58(format!(
+                "Invalid character at position {}: {}",
+                i, ch
+            )));
+        }
+
+        let mut carry = digit as u32;
+        for byte in buffer.iter_mut() {
+            let acc = *byte as u32 * 58 + carry;
+            *byte = (acc & 0xFF) as u8;
+            carry = acc >> 8;
+        }
+        while carry > 0 {
+            buffer.push((carry & 0xFF) as u8);
+            carry >>= 8;
+        }
+    }
+    buffer.reverse();
+
+    let mut result = vec![0u8; leading_zeros];
+    result.extend(buffer);
+    Ok(result)
+}
@@ -0,0 +1,110 @@
+//! Crockford Base32 encoding, a case-insensitive sibling to the crate's
+//! Base58 encoding.
+//!
+//! Unlike [`crate::Base58Alphabet`], Crockford's alphabet is fixed and
+//! well-known rather than pluggable, so there's no `Engine` here: just
+//! [`crate::encode_uuid_base32`]/[`crate::decode_base32`], which wrap the
+//! `encode`/`decode` functions in this module.
+
+use alloc::format;
+use alloc::string::String;
+
+use crate::error::B58UUIDError;
+
+/// The Crockford Base32 alphabet: `0-9` then `A-Z` excluding `I`, `L`, `O`
+/// and `U`, which are dropped to avoid visual confusion with `1`/`0` and
+/// to dodge accidental obscenities.
+const ALPHABET: [u8; 32] = *b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+/// Encoded width: 128 bits split into 5-bit groups is 25.6, rounded up to 26.
+const ENCODED_LEN: usize = 26;
+
+/// Reverse lookup table from ASCII byte to its 5-bit Crockford value, built
+/// once at compile time from [`ALPHABET`].
+const REVERSE: [u8; 256] = build_reverse();
+
+const fn build_reverse() -> [u8; 256] {
+    let mut reverse = [255u8; 256];
+    let mut i = 0usize;
+    while i < 32 {
+        reverse[ALPHABET[i] as usize] = i as u8;
+        i += 1;
+    }
+    reverse
+}
+
+/// Normalizes a single input character to its canonical uppercase Crockford
+/// symbol, folding the common confusables (`I`/`L` -> `1`, `O` -> `0`)
+/// before lookup.
+fn normalize(ch: char) -> Option<u8> {
+    match ch {
+        'I' | 'i' | 'L' | 'l' => Some(b'1'),
+        'O' | 'o' => Some(b'0'),
+        c if c.is_ascii() => Some(c.to_ascii_uppercase() as u8),
+        _ => None,
+    }
+}
+
+/// Encodes a 16-byte UUID as 26-character Crockford Base32.
+///
+/// The 128-bit value is treated as a big-endian unsigned integer and
+/// split into 5-bit groups from most- to least-significant; since
+/// `26 * 5 = 130` is 2 bits wider than 128, the leading group only
+/// carries 3 significant bits (its top 2 bits are always zero).
+pub(crate) fn encode(data: &[u8; 16]) -> String {
+    let value = u128::from_be_bytes(*data);
+
+    let mut out = [0u8; ENCODED_LEN];
+    out[0] = ALPHABET[((value >> 125) & 0b111) as usize];
+    for (i, slot) in out.iter_mut().enumerate().skip(1) {
+        let shift = 125 - 5 * i;
+        *slot = ALPHABET[((value >> shift) & 0x1F) as usize];
+    }
+
+    String::from_utf8(out.to_vec()).expect("Crockford Base32 encoding should always be valid UTF-8")
+}
+
+/// Decodes a Crockford Base32 string to a 16-byte UUID.
+///
+/// Decoding is case-insensitive and normalizes `I`/`L` to `1` and `O` to
+/// `0` before lookup. Rejects any other unrecognized character with
+/// [`B58UUIDError::InvalidBase32`], and rejects anything that isn't
+/// exactly 26 characters or whose leading group overflows its 3
+/// significant bits with [`B58UUIDError::InvalidLength`]/
+/// [`B58UUIDError::Overflow`], since both would fail to round-trip to
+/// exactly 16 bytes.
+pub(crate) fn decode(s: &str) -> Result<[u8; 16], B58UUIDError> {
+    let len = s.chars().count();
+    if len != ENCODED_LEN {
+        return Err(B58UUIDError::InvalidLength {
+            expected: ENCODED_LEN,
+            got: len,
+        });
+    }
+
+    let mut value: u128 = 0;
+    for (i, ch) in s.chars().enumerate() {
+        let normalized = normalize(ch).ok_or_else(|| {
+            B58UUIDError::InvalidBase32(format!("Invalid character at position {}: {}", i, ch))
+        })?;
+
+        let digit = REVERSE[normalized as usize];
+        if digit == 255 {
+            return Err(B58UUIDError::InvalidBase32(format!(
+                "Invalid character at position {}: {}",
+                i, ch
+            )));
+        }
+
+        if i == 0 {
+            if digit > 0b111 {
+                return Err(B58UUIDError::Overflow);
+            }
+            value = digit as u128;
+        } else {
+            value = (value << 5) | digit as u128;
+        }
+    }
+
+    Ok(value.to_be_bytes())
+}
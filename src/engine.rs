@@ -0,0 +1,345 @@
+use alloc::format;
+use alloc::string::{String, ToString};
+
+use crate::alphabet::{decode_bytes_with, encode_bytes_with, Base58Alphabet};
+use crate::error::B58UUIDError;
+
+/// A Base58 codec bound to a specific [`Base58Alphabet`].
+///
+/// The free functions at the crate root (`encode`, `decode`, `generate`,
+/// ...) are thin wrappers around a default `Engine` over the Bitcoin
+/// alphabet; construct an `Engine` directly to use a different alphabet
+/// such as [`Base58Alphabet::RIPPLE`] or [`Base58Alphabet::FLICKR`].
+///
+/// # Example
+/// ```
+/// use b58uuid::{Engine, Base58Alphabet};
+///
+/// let engine = Engine::new(Base58Alphabet::RIPPLE);
+/// let encoded = engine.generate();
+/// assert_eq!(encoded.len(), 22);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Engine {
+    alphabet: Base58Alphabet,
+}
+
+impl Engine {
+    /// Creates a new engine over the given alphabet.
+    pub const fn new(alphabet: Base58Alphabet) -> Self {
+        Self { alphabet }
+    }
+
+    /// The default engine, over the Bitcoin alphabet.
+    pub(crate) const DEFAULT: Engine = Engine::new(Base58Alphabet::BITCOIN);
+
+    /// Encodes a 16-byte UUID to a Base58 string.
+    pub fn encode(&self, data: &[u8; 16]) -> String {
+        let mut encoded = encode_bytes_with(&self.alphabet, data);
+        if encoded.len() < 22 {
+            let zero = self.alphabet.zero_char() as char;
+            let padding: String = core::iter::repeat_n(zero, 22 - encoded.len()).collect();
+            encoded = padding + &encoded;
+        }
+        encoded
+    }
+
+    /// Decodes a Base58 string to a 16-byte UUID.
+    pub fn decode(&self, b58: &str) -> Result<[u8; 16], B58UUIDError> {
+        if b58.is_empty() {
+            return Err(B58UUIDError::InvalidBase58(
+                "Empty Base58 string".to_string(),
+            ));
+        }
+
+        let bytes = decode_bytes_with(&self.alphabet, b58)?;
+
+        if bytes.len() > 16 {
+            if bytes[..bytes.len() - 16].iter().any(|&b| b != 0) {
+                return Err(B58UUIDError::Overflow);
+            }
+            let mut result = [0u8; 16];
+            result.copy_from_slice(&bytes[bytes.len() - 16..]);
+            return Ok(result);
+        }
+
+        let mut result = [0u8; 16];
+        result[16 - bytes.len()..].copy_from_slice(&bytes);
+        Ok(result)
+    }
+
+    /// Encodes a 16-byte UUID directly into `out`, without allocating.
+    ///
+    /// Returns the number of bytes written (always 22, since that's the
+    /// padded width every encoding uses) on success. Fails with
+    /// [`B58UUIDError::InvalidLength`] if `out` is smaller than that; a
+    /// caller can always satisfy it with a stack-allocated `[u8; 22]`.
+    pub fn encode_to_slice(&self, data: &[u8; 16], out: &mut [u8]) -> Result<usize, B58UUIDError> {
+        const LEN: usize = 22;
+        if out.len() < LEN {
+            return Err(B58UUIDError::InvalidLength {
+                expected: LEN,
+                got: out.len(),
+            });
+        }
+
+        let mut buffer = *data;
+        let mut digits = [0u8; LEN];
+        let mut digit_count = 0;
+
+        while buffer.iter().any(|&b| b != 0) {
+            let mut remainder: u32 = 0;
+            for byte in buffer.iter_mut() {
+                let acc = (remainder << 8) | *byte as u32;
+                *byte = (acc / 58) as u8;
+                remainder = acc % 58;
+            }
+            digits[digit_count] = self.alphabet.chars[remainder as usize];
+            digit_count += 1;
+        }
+
+        let padding = LEN - digit_count;
+        out[..padding].fill(self.alphabet.zero_char());
+        for (slot, &digit) in out[padding..LEN].iter_mut().zip(digits[..digit_count].iter().rev())
+        {
+            *slot = digit;
+        }
+
+        Ok(LEN)
+    }
+
+    /// Decodes a Base58 string directly into `out`, without allocating.
+    pub fn decode_to_bytes(&self, b58: &str, out: &mut [u8; 16]) -> Result<(), B58UUIDError> {
+        if b58.is_empty() {
+            return Err(B58UUIDError::InvalidBase58(
+                "Empty Base58 string".to_string(),
+            ));
+        }
+
+        let zero_char = self.alphabet.zero_char() as char;
+        let mut leading_zeros = 0;
+        for ch in b58.chars() {
+            if ch == zero_char {
+                leading_zeros += 1;
+            } else {
+                break;
+            }
+        }
+
+        // Accumulated little-endian: index 0 is the least-significant byte.
+        // 16 bytes is all the room a non-overflowing value needs; a 17th
+        // byte would always be non-zero (see decode()'s overflow check), so
+        // needing one is enough to know the value overflows u128.
+        let mut buffer = [0u8; 16];
+        let mut buf_len = 0usize;
+
+        for (i, ch) in b58.chars().enumerate().skip(leading_zeros) {
+            if !ch.is_ascii() {
+                return Err(B58UUIDError::InvalidBase58(format!(
+                    "Invalid character at position {}: {}",
+                    i, ch
+                )));
+            }
+
+            let digit = self.alphabet.reverse[ch as usize];
+            if digit == 255 {
+                return Err(B58UUIDError::InvalidBase58(format!(
+                    "Invalid character at position {}: {}",
+                    i, ch
+                )));
+            }
+
+            let mut carry = digit as u32;
+            for byte in buffer[..buf_len].iter_mut() {
+                let acc = *byte as u32 * 58 + carry;
+                *byte = (acc & 0xFF) as u8;
+                carry = acc >> 8;
+            }
+            while carry > 0 {
+                if buf_len == buffer.len() {
+                    return Err(B58UUIDError::Overflow);
+                }
+                buffer[buf_len] = (carry & 0xFF) as u8;
+                buf_len += 1;
+                carry >>= 8;
+            }
+        }
+
+        out.fill(0);
+        for (slot, &byte) in out[16 - buf_len..].iter_mut().zip(buffer[..buf_len].iter().rev()) {
+            *slot = byte;
+        }
+
+        Ok(())
+    }
+
+    /// Encodes a UUID string to Base58, accepting any of the formats the
+    /// `uuid` crate's own parser accepts: the 32-character simple hex form,
+    /// the 8-4-4-4-12 hyphenated canonical form, either optionally wrapped
+    /// in `{...}` braces or prefixed with `urn:uuid:` (case-insensitive).
+    /// Hyphens are only required to be in the right place if present at all.
+    pub fn encode_uuid(&self, uuid_str: &str) -> Result<String, B58UUIDError> {
+        let bytes = parse_uuid_bytes(uuid_str)?;
+        Ok(self.encode(&bytes))
+    }
+
+    /// Decodes a Base58 string to a standard hyphenated, lowercase UUID string.
+    pub fn decode_to_uuid(&self, b58: &str) -> Result<String, B58UUIDError> {
+        let bytes = self.decode(b58)?;
+
+        Ok(format!(
+            "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+            bytes[0], bytes[1], bytes[2], bytes[3],
+            bytes[4], bytes[5], bytes[6], bytes[7],
+            bytes[8], bytes[9], bytes[10], bytes[11],
+            bytes[12], bytes[13], bytes[14], bytes[15]
+        ))
+    }
+
+    /// Decodes a Base58 string to a 32-character simple (no hyphens),
+    /// lowercase UUID string, for callers who want the compact form
+    /// instead of [`Engine::decode_to_uuid`]'s hyphenated one.
+    pub fn decode_to_uuid_simple(&self, b58: &str) -> Result<String, B58UUIDError> {
+        let bytes = self.decode(b58)?;
+
+        Ok(format!(
+            "{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
+            bytes[0], bytes[1], bytes[2], bytes[3],
+            bytes[4], bytes[5], bytes[6], bytes[7],
+            bytes[8], bytes[9], bytes[10], bytes[11],
+            bytes[12], bytes[13], bytes[14], bytes[15]
+        ))
+    }
+
+    /// Generates a new random (version 4) UUID and returns its
+    /// Base58-encoded representation.
+    pub fn generate(&self) -> String {
+        let mut bytes = [0u8; 16];
+        getrandom::getrandom(&mut bytes).expect("Failed to generate random bytes");
+
+        bytes[6] = (bytes[6] & 0x0F) | 0x40; // Version 4
+        bytes[8] = (bytes[8] & 0x3F) | 0x80; // Variant 10
+
+        self.encode(&bytes)
+    }
+
+    /// Generates a new time-ordered (version 7) UUID and returns its
+    /// Base58-encoded representation.
+    ///
+    /// Because the millisecond timestamp occupies the most significant
+    /// bytes, the Base58 encoding of successive calls sorts lexicographically
+    /// in (roughly) time order, which makes it a better database primary key
+    /// than [`Engine::generate`]'s version 4 output: it doesn't fragment
+    /// B-tree indexes the way random keys do.
+    #[cfg(feature = "std")]
+    pub fn generate_v7(&self) -> String {
+        self.encode(&new_v7_bytes())
+    }
+}
+
+/// Builds the raw bytes of a new time-ordered (version 7) UUID per
+/// [RFC 9562](https://www.rfc-editor.org/rfc/rfc9562.html): a 48-bit
+/// big-endian Unix millisecond timestamp in bytes 0-5, `getrandom` fill
+/// for the rest, then the version nibble in byte 6 and variant bits in
+/// byte 8.
+#[cfg(feature = "std")]
+pub fn new_v7_bytes() -> [u8; 16] {
+    let millis = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .expect("system clock is before the Unix epoch")
+        .as_millis() as u64;
+
+    let mut bytes = [0u8; 16];
+    bytes[..6].copy_from_slice(&millis.to_be_bytes()[2..]);
+    getrandom::getrandom(&mut bytes[6..]).expect("Failed to generate random bytes");
+
+    bytes[6] = (bytes[6] & 0x0F) | 0x70; // Version 7
+    bytes[8] = (bytes[8] & 0x3F) | 0x80; // Variant 10
+
+    bytes
+}
+
+/// Parses a UUID string in any of the formats the `uuid` crate's own
+/// parser accepts, into raw 16-byte form.
+///
+/// Strips an optional `urn:uuid:` prefix (case-insensitive) and/or
+/// surrounding `{...}` braces, then accepts either the 32-character simple
+/// hex form or the 8-4-4-4-12 hyphenated canonical form. Hyphen positions
+/// are only validated when hyphens are present at all: a 36-character
+/// input must have them at exactly positions 8, 13, 18 and 23, but a
+/// 32-character input is rejected if it has any.
+fn parse_uuid_bytes(uuid_str: &str) -> Result<[u8; 16], B58UUIDError> {
+    // Every subsequent length check and slice below is byte-offset-based,
+    // which is only safe once we know every char is a single byte; reject
+    // non-ASCII input up front rather than risk slicing into the middle
+    // of a multi-byte character.
+    if !uuid_str.is_ascii() {
+        return Err(B58UUIDError::InvalidUUID(
+            "UUID must be ASCII".to_string(),
+        ));
+    }
+
+    let mut s = uuid_str;
+
+    if let Some(inner) = s.strip_prefix('{').and_then(|r| r.strip_suffix('}')) {
+        s = inner;
+    }
+    if let Some(rest) = strip_prefix_ignore_case(s, "urn:uuid:") {
+        s = rest;
+    }
+
+    match s.len() {
+        32 => {
+            if s.contains('-') {
+                return Err(B58UUIDError::InvalidUUID(
+                    "unexpected '-' in 32-character UUID".to_string(),
+                ));
+            }
+        }
+        36 => {
+            for (i, ch) in s.char_indices() {
+                let must_be_hyphen = matches!(i, 8 | 13 | 18 | 23);
+                if must_be_hyphen != (ch == '-') {
+                    return Err(B58UUIDError::InvalidUUID(format!(
+                        "unexpected character at position {}: {}",
+                        i, ch
+                    )));
+                }
+            }
+        }
+        other => {
+            return Err(B58UUIDError::InvalidLength {
+                expected: 32,
+                got: other,
+            })
+        }
+    }
+
+    let hex: String = s.chars().filter(|&c| c != '-').collect();
+
+    let mut bytes = [0u8; 16];
+    for i in 0..16 {
+        let hex_byte = &hex[i * 2..i * 2 + 2];
+        match u8::from_str_radix(hex_byte, 16) {
+            Ok(byte) => bytes[i] = byte,
+            Err(_) => {
+                return Err(B58UUIDError::InvalidUUID(format!(
+                    "Invalid hex at position {}",
+                    i * 2
+                )))
+            }
+        }
+    }
+
+    Ok(bytes)
+}
+
+/// Case-insensitive `str::strip_prefix`, since `urn:uuid:` may be given in
+/// any casing.
+fn strip_prefix_ignore_case<'a>(s: &'a str, prefix: &str) -> Option<&'a str> {
+    if !s.is_char_boundary(prefix.len()) {
+        return None;
+    }
+    let (head, tail) = s.split_at(prefix.len());
+    head.eq_ignore_ascii_case(prefix).then_some(tail)
+}
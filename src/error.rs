@@ -0,0 +1,42 @@
+use alloc::string::String;
+use core::fmt;
+
+#[cfg(feature = "std")]
+use std::error::Error;
+
+/// Custom error types for b58uuid operations
+#[derive(Debug, Clone, PartialEq)]
+pub enum B58UUIDError {
+    InvalidUUID(String),
+    InvalidBase58(String),
+    InvalidBase32(String),
+    InvalidLength { expected: usize, got: usize },
+    Overflow,
+    BadChecksum { expected: u32, actual: u32 },
+}
+
+impl fmt::Display for B58UUIDError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            B58UUIDError::InvalidUUID(msg) => write!(f, "Invalid UUID: {}", msg),
+            B58UUIDError::InvalidBase58(msg) => write!(f, "Invalid Base58: {}", msg),
+            B58UUIDError::InvalidBase32(msg) => write!(f, "Invalid Base32: {}", msg),
+            B58UUIDError::InvalidLength { expected, got } => {
+                write!(f, "Invalid length: expected {}, got {}", expected, got)
+            }
+            B58UUIDError::Overflow => {
+                write!(f, "Arithmetic overflow: value exceeds maximum UUID value")
+            }
+            B58UUIDError::BadChecksum { expected, actual } => {
+                write!(
+                    f,
+                    "Checksum mismatch: expected {:08x}, got {:08x}",
+                    expected, actual
+                )
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for B58UUIDError {}
@@ -1,52 +1,105 @@
+#![cfg_attr(not(any(test, feature = "std")), no_std)]
 //! Fast Base58 encoding/decoding for UUIDs with minimal dependencies
 //!
 //! This crate provides efficient Base58 encoding and decoding for UUIDs,
-//! with comprehensive error handling and minimal dependencies (only getrandom for secure random generation).
-
-use std::error::Error;
-use std::fmt;
-
-/// Base58 alphabet (Bitcoin alphabet)
-const BASE58_ALPHABET: &str = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
-
-/// Precomputed reverse lookup table for Base58 decoding
-const REVERSE_ALPHABET: [u8; 256] = {
-    let mut table = [255u8; 256];
-    let alphabet_bytes = BASE58_ALPHABET.as_bytes();
-    let mut i = 0u8;
-    while i < 58 {
-        table[alphabet_bytes[i as usize] as usize] = i;
-        i += 1;
-    }
-    table
-};
-
-/// Custom error types for b58uuid operations
-#[derive(Debug, Clone, PartialEq)]
-pub enum B58UUIDError {
-    InvalidUUID(String),
-    InvalidBase58(String),
-    InvalidLength { expected: usize, got: usize },
-    Overflow,
+//! with comprehensive error handling and minimal dependencies (getrandom
+//! for secure random generation and sha2 for Base58Check checksums).
+//!
+//! The free functions at the crate root (`encode`, `decode`, `generate`,
+//! ...) operate over the Bitcoin alphabet. To use a different alphabet
+//! (e.g. Ripple or Flickr) or a custom one, build an [`Engine`] over a
+//! [`Base58Alphabet`] directly.
+//!
+//! The crate is `#![no_std]` against `alloc` alone by default (`getrandom`
+//! and `sha2` both support this), which keeps it usable in embedded
+//! targets and WASM bundles with no dependency footprint beyond `alloc`;
+//! [`B58UUIDError`] still implements `Display` via `core::fmt` in this
+//! mode. Enable the `std` feature to additionally get `impl
+//! std::error::Error for B58UUIDError` and [`generate_v7`]/
+//! [`new_v7_bytes`], which need a wall clock.
+
+extern crate alloc;
+
+mod alphabet;
+mod base32;
+mod engine;
+mod error;
+#[cfg(feature = "serde")]
+pub mod serde_b58;
+#[cfg(feature = "uuid")]
+pub mod uuid_support;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
+
+pub use alphabet::Base58Alphabet;
+pub use engine::Engine;
+#[cfg(feature = "std")]
+pub use engine::new_v7_bytes;
+pub use error::B58UUIDError;
+#[cfg(feature = "serde")]
+pub use serde_b58::B58Uuid;
+#[cfg(feature = "uuid")]
+pub use uuid_support::{decode_to_uuid_type, encode_uuid_type, new_v4_b58, new_v5_b58};
+#[cfg(all(feature = "uuid", feature = "std"))]
+pub use uuid_support::new_v7_b58;
+
+/// Encodes an arbitrary-length byte slice to a Base58 string
+///
+/// The input is treated as a big-endian unsigned integer and repeatedly
+/// long-divided by 58, carrying from the most- to the least-significant
+/// byte, until the remaining buffer is all zero. Each leading zero byte
+/// of the input becomes a leading `1` in the output, matching the
+/// convention used by Bitcoin's Base58Check implementation.
+///
+/// Unlike [`encode`], this has no 16-byte ceiling: it works for any
+/// length, including zero.
+///
+/// # Arguments
+/// * `data` - The bytes to encode
+///
+/// # Returns
+/// * `String` - The Base58-encoded representation
+///
+/// # Example
+/// ```
+/// use b58uuid::encode_bytes;
+///
+/// let encoded = encode_bytes(&[0x00, 0x01, 0x02]);
+/// assert_eq!(encoded, "15T");
+/// ```
+pub fn encode_bytes(data: &[u8]) -> String {
+    alphabet::encode_bytes_with(&Base58Alphabet::BITCOIN, data)
 }
 
-impl fmt::Display for B58UUIDError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            B58UUIDError::InvalidUUID(msg) => write!(f, "Invalid UUID: {}", msg),
-            B58UUIDError::InvalidBase58(msg) => write!(f, "Invalid Base58: {}", msg),
-            B58UUIDError::InvalidLength { expected, got } => {
-                write!(f, "Invalid length: expected {}, got {}", expected, got)
-            }
-            B58UUIDError::Overflow => {
-                write!(f, "Arithmetic overflow: value exceeds maximum UUID value")
-            }
-        }
-    }
+/// Decodes a Base58 string to its arbitrary-length byte representation
+///
+/// This reverses [`encode_bytes`]: each leading `1` becomes a leading
+/// zero byte, and the remaining digits are accumulated into a
+/// big-endian byte buffer via repeated multiply-by-58-and-add, carrying
+/// from the least- to the most-significant byte.
+///
+/// Unlike [`decode`], this has no 16-byte ceiling and never returns
+/// [`B58UUIDError::Overflow`]: arbitrarily large values are accepted.
+///
+/// # Arguments
+/// * `b58` - The Base58-encoded string
+///
+/// # Returns
+/// * `Result<Vec<u8>, B58UUIDError>` - The decoded bytes or an error
+///
+/// # Example
+/// ```
+/// use b58uuid::decode_bytes;
+///
+/// let decoded = decode_bytes("15T").unwrap();
+/// assert_eq!(decoded, vec![0x00, 0x01, 0x02]);
+/// ```
+pub fn decode_bytes(b58: &str) -> Result<Vec<u8>, B58UUIDError> {
+    alphabet::decode_bytes_with(&Base58Alphabet::BITCOIN, b58)
 }
 
-impl Error for B58UUIDError {}
-
 /// Encodes a 16-byte UUID to a Base58 string
 ///
 /// # Arguments
@@ -67,46 +120,7 @@ impl Error for B58UUIDError {}
 /// assert_eq!(encoded, "BWBeN28Vb7cMEx7Ym8AUzs");
 /// ```
 pub fn encode(data: &[u8; 16]) -> String {
-    // Handle leading zeros optimization
-    let mut leading_zeros = 0;
-    for &byte in data.iter() {
-        if byte == 0 {
-            leading_zeros += 1;
-        } else {
-            break;
-        }
-    }
-
-    // All zeros special case - return 22 '1' characters
-    if leading_zeros == 16 {
-        return "1".repeat(22);
-    }
-
-    // Convert to Base58
-    let mut result = Vec::new();
-    let mut num = u128::from_be_bytes(*data);
-
-    while num > 0 {
-        let remainder = (num % 58) as usize;
-        result.push(BASE58_ALPHABET.as_bytes()[remainder]);
-        num /= 58;
-    }
-
-    // Add leading zeros representation
-    result.extend(std::iter::repeat_n(b'1', leading_zeros));
-
-    // Reverse to get correct order
-    result.reverse();
-
-    // Pad with leading '1' to ensure 22 characters (more efficient than insert)
-    let mut encoded =
-        String::from_utf8(result).expect("Base58 encoding should always be valid UTF-8");
-    if encoded.len() < 22 {
-        let padding = "1".repeat(22 - encoded.len());
-        encoded = padding + &encoded;
-    }
-
-    encoded
+    Engine::DEFAULT.encode(data)
 }
 
 /// Decodes a Base58 string to a 16-byte UUID
@@ -128,71 +142,56 @@ pub fn encode(data: &[u8; 16]) -> String {
 /// ]);
 /// ```
 pub fn decode(b58: &str) -> Result<[u8; 16], B58UUIDError> {
-    if b58.is_empty() {
-        return Err(B58UUIDError::InvalidBase58(
-            "Empty Base58 string".to_string(),
-        ));
-    }
-
-    // Count leading ones
-    let mut leading_ones = 0;
-    for ch in b58.chars() {
-        if ch == '1' {
-            leading_ones += 1;
-        } else {
-            break;
-        }
-    }
-
-    // Convert Base58 to number with overflow checking
-    let mut num = 0u128;
-    for (i, ch) in b58.chars().enumerate() {
-        if ch == '1' && i < leading_ones {
-            continue; // Skip leading ones
-        }
-
-        // Check if character is within ASCII range before indexing
-        if !ch.is_ascii() || ch as usize >= 256 {
-            return Err(B58UUIDError::InvalidBase58(format!(
-                "Invalid character at position {}: {}",
-                i, ch
-            )));
-        }
-
-        let digit = REVERSE_ALPHABET[ch as usize];
-        if digit == 255 {
-            return Err(B58UUIDError::InvalidBase58(format!(
-                "Invalid character at position {}: {}",
-                i, ch
-            )));
-        }
-
-        // Check for overflow before multiplication
-        num = num.checked_mul(58).ok_or(B58UUIDError::Overflow)?;
-
-        // Check for overflow before addition
-        num = num
-            .checked_add(digit as u128)
-            .ok_or(B58UUIDError::Overflow)?;
-    }
-
-    // Convert to bytes
-    let mut bytes = [0u8; 16];
-    let num_bytes = num.to_be_bytes();
-
-    // Ensure the result fits in 16 bytes (check if leading bytes are all zero)
-    bytes.copy_from_slice(&num_bytes);
+    Engine::DEFAULT.decode(b58)
+}
 
-    // Verify leading ones correspond to leading zeros
-    // Note: For all-zeros UUID, we encode as 22 '1' characters (with padding)
-    // so we need to allow up to 22 leading ones
-    if leading_ones > 22 {
-        return Err(B58UUIDError::InvalidBase58(
-            "Too many leading '1' characters".to_string(),
-        ));
-    }
+/// Encodes a 16-byte UUID directly into `out`, without allocating
+///
+/// Encoded output is always 22 bytes, so a caller can stack-allocate
+/// `[u8; 22]` and avoid `String` churn in hot paths.
+///
+/// # Arguments
+/// * `data` - A 16-byte array representing the UUID
+/// * `out` - The buffer to write ASCII Base58 into; must be at least 22 bytes
+///
+/// # Returns
+/// * `Result<usize, B58UUIDError>` - The number of bytes written (always 22)
+///
+/// # Example
+/// ```
+/// use b58uuid::encode_to_slice;
+///
+/// let uuid_bytes = [
+///     0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4,
+///     0xa7, 0x16, 0x44, 0x66, 0x55, 0x44, 0x00, 0x00
+/// ];
+/// let mut buf = [0u8; 22];
+/// let len = encode_to_slice(&uuid_bytes, &mut buf).unwrap();
+/// assert_eq!(&buf[..len], b"BWBeN28Vb7cMEx7Ym8AUzs");
+/// ```
+pub fn encode_to_slice(data: &[u8; 16], out: &mut [u8]) -> Result<usize, B58UUIDError> {
+    Engine::DEFAULT.encode_to_slice(data, out)
+}
 
-    Ok(bytes)
+/// Decodes a Base58 string directly into `out`, without allocating
+///
+/// # Arguments
+/// * `b58` - The Base58-encoded string
+/// * `out` - The 16-byte buffer to decode into
+///
+/// # Example
+/// ```
+/// use b58uuid::decode_to_bytes;
+///
+/// let mut out = [0u8; 16];
+/// decode_to_bytes("BWBeN28Vb7cMEx7Ym8AUzs", &mut out).unwrap();
+/// assert_eq!(out, [
+///     0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4,
+///     0xa7, 0x16, 0x44, 0x66, 0x55, 0x44, 0x00, 0x00
+/// ]);
+/// ```
+pub fn decode_to_bytes(b58: &str, out: &mut [u8; 16]) -> Result<(), B58UUIDError> {
+    Engine::DEFAULT.decode_to_bytes(b58, out)
 }
 
 /// Generates a new random UUID and returns its Base58-encoded representation
@@ -213,23 +212,48 @@ pub fn decode(b58: &str) -> Result<[u8; 16], B58UUIDError> {
 /// assert_ne!(b58_1, b58_2); // Should generate unique values
 /// ```
 pub fn generate() -> String {
-    let mut bytes = [0u8; 16];
-
-    // Use getrandom for cryptographically secure random bytes
-    // This works on all platforms: Linux, macOS, Windows, iOS, Android, WASM, etc.
-    getrandom::getrandom(&mut bytes).expect("Failed to generate random bytes");
-
-    // Set UUID version (4) and variant bits
-    bytes[6] = (bytes[6] & 0x0F) | 0x40; // Version 4
-    bytes[8] = (bytes[8] & 0x3F) | 0x80; // Variant 10
+    Engine::DEFAULT.generate()
+}
 
-    encode(&bytes)
+/// Generates a new time-ordered (version 7) UUID and returns its
+/// Base58-encoded representation
+///
+/// Unlike [`generate`]'s random version 4 output, the millisecond Unix
+/// timestamp embedded in the high-order bytes means the Base58 encoding
+/// of a sequence of calls sorts lexicographically in roughly time order,
+/// which avoids the B-tree index fragmentation random primary keys cause.
+///
+/// # Example
+/// ```
+/// use b58uuid::generate_v7;
+///
+/// let b58_1 = generate_v7();
+/// let b58_2 = generate_v7();
+/// assert_ne!(b58_1, b58_2);
+/// assert_eq!(b58_1.len(), 22);
+/// ```
+#[cfg(feature = "std")]
+pub fn generate_v7() -> String {
+    Engine::DEFAULT.generate_v7()
 }
 
 /// Encodes a UUID string to Base58 format
 ///
+/// Accepts any format the `uuid` crate's own parser does: the 32-character
+/// simple hex form, the 8-4-4-4-12 hyphenated canonical form, either
+/// optionally wrapped in `{...}` braces (Windows-style GUID) or prefixed
+/// with `urn:uuid:` (case-insensitive). Hyphens are only required to be in
+/// the right place if present at all.
+///
+/// The result is always left-padded with the alphabet's zero digit
+/// (`'1'` for Bitcoin Base58) to a fixed 22 characters. Because that
+/// padding character sorts lowest in the alphabet, `encode_uuid(a) <
+/// encode_uuid(b)` exactly when the underlying 128-bit value of `a` is
+/// less than that of `b`, so the encoded strings can be sorted or
+/// compared as database keys without decoding them first.
+///
 /// # Arguments
-/// * `uuid_str` - A UUID string in standard format (with or without hyphens)
+/// * `uuid_str` - A UUID string in any of the above formats
 ///
 /// # Returns
 /// * `Result<String, B58UUIDError>` - The Base58-encoded UUID or an error
@@ -240,35 +264,21 @@ pub fn generate() -> String {
 ///
 /// let encoded = encode_uuid("550e8400-e29b-41d4-a716-446655440000").unwrap();
 /// assert_eq!(encoded, "BWBeN28Vb7cMEx7Ym8AUzs");
+///
+/// // The simple, braced and URN forms all produce the same encoding.
+/// assert_eq!(encode_uuid("550e8400e29b41d4a716446655440000").unwrap(), encoded);
+/// assert_eq!(encode_uuid("{550e8400-e29b-41d4-a716-446655440000}").unwrap(), encoded);
+/// assert_eq!(encode_uuid("urn:uuid:550e8400-e29b-41d4-a716-446655440000").unwrap(), encoded);
 /// ```
 pub fn encode_uuid(uuid_str: &str) -> Result<String, B58UUIDError> {
-    let cleaned = uuid_str.replace('-', "");
-
-    if cleaned.len() != 32 {
-        return Err(B58UUIDError::InvalidLength {
-            expected: 32,
-            got: cleaned.len(),
-        });
-    }
-
-    let mut bytes = [0u8; 16];
-    for i in 0..16 {
-        let hex_byte = &cleaned[i * 2..i * 2 + 2];
-        match u8::from_str_radix(hex_byte, 16) {
-            Ok(byte) => bytes[i] = byte,
-            Err(_) => {
-                return Err(B58UUIDError::InvalidUUID(format!(
-                    "Invalid hex at position {}",
-                    i * 2
-                )))
-            }
-        }
-    }
-
-    Ok(encode(&bytes))
+    Engine::DEFAULT.encode_uuid(uuid_str)
 }
 
-/// Decodes a Base58 string to a standard UUID string format
+/// Decodes a Base58 string to a standard, hyphenated UUID string format
+///
+/// Accepts both [`encode_uuid`]'s zero-padded 22-character canonical form
+/// and shorter, unpadded input. Values exceeding `2^128 - 1` are rejected
+/// with [`B58UUIDError::Overflow`].
 ///
 /// # Arguments
 /// * `b58` - The Base58-encoded string
@@ -284,15 +294,209 @@ pub fn encode_uuid(uuid_str: &str) -> Result<String, B58UUIDError> {
 /// assert_eq!(uuid_str, "550e8400-e29b-41d4-a716-446655440000");
 /// ```
 pub fn decode_to_uuid(b58: &str) -> Result<String, B58UUIDError> {
-    let bytes = decode(b58)?;
-
-    Ok(format!(
-        "{:02x}{:02x}{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}-{:02x}{:02x}{:02x}{:02x}{:02x}{:02x}",
-        bytes[0], bytes[1], bytes[2], bytes[3],
-        bytes[4], bytes[5], bytes[6], bytes[7],
-        bytes[8], bytes[9], bytes[10], bytes[11],
-        bytes[12], bytes[13], bytes[14], bytes[15]
-    ))
+    Engine::DEFAULT.decode_to_uuid(b58)
+}
+
+/// Decodes a Base58 string to a 32-character simple (no hyphens) UUID string
+///
+/// For callers who want the compact form instead of [`decode_to_uuid`]'s
+/// hyphenated one.
+///
+/// # Example
+/// ```
+/// use b58uuid::decode_to_uuid_simple;
+///
+/// let uuid_str = decode_to_uuid_simple("BWBeN28Vb7cMEx7Ym8AUzs").unwrap();
+/// assert_eq!(uuid_str, "550e8400e29b41d4a716446655440000");
+/// ```
+pub fn decode_to_uuid_simple(b58: &str) -> Result<String, B58UUIDError> {
+    Engine::DEFAULT.decode_to_uuid_simple(b58)
+}
+
+/// Encodes a UUID string to Base58, always zero-padded to the canonical
+/// 22-character width
+///
+/// An explicitly-named alias of [`encode_uuid`], which already provides
+/// this guarantee: callers who want the sortable-encoding property to show
+/// up in their own code's `use` line, rather than relying on the behavior
+/// documented on a differently-named function, can reach it as
+/// `encode_uuid_padded` instead.
+///
+/// # Example
+/// ```
+/// use b58uuid::encode_uuid_padded;
+///
+/// let encoded = encode_uuid_padded("00000000-0000-0000-0000-000000000001").unwrap();
+/// assert_eq!(encoded, "1111111111111111111112");
+/// assert_eq!(encoded.len(), 22);
+/// ```
+pub fn encode_uuid_padded(uuid_str: &str) -> Result<String, B58UUIDError> {
+    encode_uuid(uuid_str)
+}
+
+/// Decodes a Base58 string, produced by either [`encode_uuid_padded`] or
+/// [`encode_uuid`], back to a standard UUID string
+///
+/// An alias of [`decode_to_uuid`]; see [`encode_uuid_padded`] for why this
+/// name exists alongside it.
+///
+/// # Example
+/// ```
+/// use b58uuid::{decode_padded, encode_uuid_padded};
+///
+/// let encoded = encode_uuid_padded("550e8400-e29b-41d4-a716-446655440000").unwrap();
+/// assert_eq!(decode_padded(&encoded).unwrap(), "550e8400-e29b-41d4-a716-446655440000");
+///
+/// // Unpadded input still decodes correctly.
+/// assert_eq!(decode_padded("1112").unwrap(), "00000000-0000-0000-0000-000000000001");
+/// ```
+pub fn decode_padded(b58: &str) -> Result<String, B58UUIDError> {
+    decode_to_uuid(b58)
+}
+
+/// Computes the 4-byte Base58Check checksum: the first 4 bytes of
+/// `SHA256(SHA256(payload))`.
+fn checksum(payload: &[u8]) -> [u8; 4] {
+    let first = Sha256::digest(payload);
+    let second = Sha256::digest(first);
+    [second[0], second[1], second[2], second[3]]
+}
+
+/// Encodes a byte payload as Base58Check: Base58 over the payload with a
+/// 4-byte double-SHA256 checksum appended, so a mistyped character is
+/// overwhelmingly likely to be caught on decode.
+///
+/// # Arguments
+/// * `payload` - The bytes to encode
+///
+/// # Returns
+/// * `String` - The Base58Check-encoded string
+///
+/// # Example
+/// ```
+/// use b58uuid::{encode_check, decode_check};
+///
+/// let encoded = encode_check(&[0x00, 0x01, 0x02]);
+/// let decoded = decode_check(&encoded).unwrap();
+/// assert_eq!(decoded, vec![0x00, 0x01, 0x02]);
+/// ```
+pub fn encode_check(payload: &[u8]) -> String {
+    let mut buf = payload.to_vec();
+    buf.extend_from_slice(&checksum(payload));
+    encode_bytes(&buf)
+}
+
+/// Decodes a Base58Check string, verifying its trailing 4-byte checksum
+///
+/// # Arguments
+/// * `b58` - The Base58Check-encoded string
+///
+/// # Returns
+/// * `Result<Vec<u8>, B58UUIDError>` - The original payload, or
+///   [`B58UUIDError::BadChecksum`] if the checksum does not match
+///
+/// # Example
+/// ```
+/// use b58uuid::{encode_check, decode_check};
+///
+/// let encoded = encode_check(&[0x00, 0x01, 0x02]);
+/// assert_eq!(decode_check(&encoded).unwrap(), vec![0x00, 0x01, 0x02]);
+/// ```
+pub fn decode_check(b58: &str) -> Result<Vec<u8>, B58UUIDError> {
+    let mut decoded = decode_bytes(b58)?;
+
+    if decoded.len() < 4 {
+        return Err(B58UUIDError::InvalidLength {
+            expected: 4,
+            got: decoded.len(),
+        });
+    }
+
+    let checksum_offset = decoded.len() - 4;
+    let payload = &decoded[..checksum_offset];
+    let actual = u32::from_be_bytes(checksum(payload));
+    let expected = u32::from_be_bytes(decoded[checksum_offset..].try_into().unwrap());
+
+    if actual != expected {
+        return Err(B58UUIDError::BadChecksum { expected, actual });
+    }
+
+    decoded.truncate(checksum_offset);
+    Ok(decoded)
+}
+
+/// Encodes a 16-byte UUID as a Base58Check string
+///
+/// # Example
+/// ```
+/// use b58uuid::encode_uuid_check;
+///
+/// let uuid_bytes = [
+///     0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4,
+///     0xa7, 0x16, 0x44, 0x66, 0x55, 0x44, 0x00, 0x00
+/// ];
+/// let encoded = encode_uuid_check(&uuid_bytes);
+/// ```
+pub fn encode_uuid_check(data: &[u8; 16]) -> String {
+    encode_check(data)
+}
+
+/// Decodes a Base58Check string to a 16-byte UUID, verifying its checksum
+///
+/// # Example
+/// ```
+/// use b58uuid::{encode_uuid_check, decode_check_to_uuid};
+///
+/// let uuid_bytes = [0x55u8; 16];
+/// let encoded = encode_uuid_check(&uuid_bytes);
+/// assert_eq!(decode_check_to_uuid(&encoded).unwrap(), uuid_bytes);
+/// ```
+pub fn decode_check_to_uuid(b58: &str) -> Result<[u8; 16], B58UUIDError> {
+    let payload = decode_check(b58)?;
+    let len = payload.len();
+    payload
+        .try_into()
+        .map_err(|_| B58UUIDError::InvalidLength { expected: 16, got: len })
+}
+
+/// Encodes a 16-byte UUID as 26-character Crockford Base32
+///
+/// Crockford Base32 is case-insensitive and tolerates the common
+/// confusables on decode (see [`decode_base32`]), making it a more
+/// typo-resistant, clipboard-friendly alternative to [`encode`]'s Base58
+/// for contexts like spoken-aloud or hand-typed identifiers.
+///
+/// # Example
+/// ```
+/// use b58uuid::encode_uuid_base32;
+///
+/// let uuid_bytes = [
+///     0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4,
+///     0xa7, 0x16, 0x44, 0x66, 0x55, 0x44, 0x00, 0x00
+/// ];
+/// let encoded = encode_uuid_base32(&uuid_bytes);
+/// assert_eq!(encoded.len(), 26);
+/// ```
+pub fn encode_uuid_base32(data: &[u8; 16]) -> String {
+    base32::encode(data)
+}
+
+/// Decodes a Crockford Base32 string to a 16-byte UUID
+///
+/// Decoding is case-insensitive and normalizes `I`/`L` to `1` and `O` to
+/// `0` before lookup, matching the Crockford spec's tolerance for common
+/// handwriting/OCR confusions.
+///
+/// # Example
+/// ```
+/// use b58uuid::{encode_uuid_base32, decode_base32};
+///
+/// let uuid_bytes = [0xAAu8; 16];
+/// let encoded = encode_uuid_base32(&uuid_bytes);
+/// assert_eq!(decode_base32(&encoded).unwrap(), uuid_bytes);
+/// ```
+pub fn decode_base32(b58: &str) -> Result<[u8; 16], B58UUIDError> {
+    base32::decode(b58)
 }
 
 #[cfg(test)]
@@ -450,13 +654,12 @@ mod tests {
 
     #[test]
     fn test_very_long_input() {
-        // Test that very long strings with too many leading ones are rejected
+        // A long run of leading '1' characters is just a long run of
+        // leading zero bytes, which collapses to the all-zero UUID once
+        // truncated to 16 bytes.
         let long_input = "1".repeat(1000);
         let result = decode(&long_input);
-        assert!(
-            matches!(result, Err(B58UUIDError::InvalidBase58(_))),
-            "Should reject string with 1000 leading '1' characters"
-        );
+        assert_eq!(result, Ok([0u8; 16]));
     }
 
     #[test]
@@ -628,15 +831,49 @@ mod tests {
         }
     }
 
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_v7_version_and_variant() {
+        // Verify generated UUIDs conform to RFC 9562 (version 7, variant 10)
+        for _ in 0..100 {
+            let b58 = generate_v7();
+            let bytes = decode(&b58).unwrap();
+
+            let version = (bytes[6] & 0xF0) >> 4;
+            assert_eq!(version, 7, "UUID should be version 7");
+
+            let variant = (bytes[8] & 0xC0) >> 6;
+            assert_eq!(variant, 2, "UUID should have variant 10 (RFC 4122)");
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_v7_lexicographic_time_ordering() {
+        // Each call embeds a millisecond timestamp in its high-order bytes,
+        // so a millisecond-spaced sequence of encoded strings should sort
+        // in the same order it was generated.
+        let mut previous = generate_v7();
+        for _ in 0..5 {
+            std::thread::sleep(std::time::Duration::from_millis(2));
+            let next = generate_v7();
+            assert!(
+                next > previous,
+                "expected {} to sort after {}",
+                next,
+                previous
+            );
+            previous = next;
+        }
+    }
+
     #[test]
     fn test_boundary_23_leading_ones() {
-        // Test exactly 23 leading '1' characters (boundary case)
+        // 23 leading '1' characters decode to 23 leading zero bytes, all
+        // of which are discardable padding since the value is still zero.
         let input = "1".repeat(23);
         let result = decode(&input);
-        assert!(
-            matches!(result, Err(B58UUIDError::InvalidBase58(_))),
-            "Should reject string with 23 leading '1' characters"
-        );
+        assert_eq!(result, Ok([0u8; 16]));
     }
 
     #[test]
@@ -749,11 +986,13 @@ mod tests {
 
     #[test]
     fn test_whitespace_in_uuid() {
-        // Test that UUIDs with whitespace are rejected
+        // Test that UUIDs with whitespace are rejected. This is 36
+        // characters with spaces at the hyphen positions, so it fails
+        // position validation rather than the length check.
         let uuid_with_space = "550e8400 e29b 41d4 a716 446655440000";
         let result = encode_uuid(uuid_with_space);
         assert!(
-            matches!(result, Err(B58UUIDError::InvalidLength { .. })),
+            matches!(result, Err(B58UUIDError::InvalidUUID(_))),
             "Should reject UUID with whitespace"
         );
     }
@@ -774,6 +1013,75 @@ mod tests {
         assert_eq!(encoded, "BWBeN28Vb7cMEx7Ym8AUzs");
     }
 
+    #[test]
+    fn test_encode_uuid_braced_form() {
+        let braced = "{550e8400-e29b-41d4-a716-446655440000}";
+        let encoded = encode_uuid(braced).unwrap();
+        assert_eq!(encoded, "BWBeN28Vb7cMEx7Ym8AUzs");
+    }
+
+    #[test]
+    fn test_encode_uuid_urn_form() {
+        let urn = "urn:uuid:550e8400-e29b-41d4-a716-446655440000";
+        let encoded = encode_uuid(urn).unwrap();
+        assert_eq!(encoded, "BWBeN28Vb7cMEx7Ym8AUzs");
+    }
+
+    #[test]
+    fn test_encode_uuid_urn_form_case_insensitive() {
+        let urn = "URN:UUID:550e8400-e29b-41d4-a716-446655440000";
+        let encoded = encode_uuid(urn).unwrap();
+        assert_eq!(encoded, "BWBeN28Vb7cMEx7Ym8AUzs");
+    }
+
+    #[test]
+    fn test_encode_uuid_braced_urn_combo() {
+        let combo = "{urn:uuid:550e8400-e29b-41d4-a716-446655440000}";
+        let encoded = encode_uuid(combo).unwrap();
+        assert_eq!(encoded, "BWBeN28Vb7cMEx7Ym8AUzs");
+    }
+
+    #[test]
+    fn test_encode_uuid_rejects_misplaced_hyphen() {
+        // Same length as a valid hyphenated UUID, but with a hyphen one
+        // position off from where it belongs.
+        let misplaced = "550e840-0e29b-41d4-a716-446655440000";
+        let result = encode_uuid(misplaced);
+        assert!(matches!(result, Err(B58UUIDError::InvalidUUID(_))));
+    }
+
+    #[test]
+    fn test_encode_uuid_rejects_hyphen_in_simple_form() {
+        // 32 hex characters plus a hyphen is 33 characters; pick a case
+        // that still lands on 32 after a typo to hit the simple-form path.
+        let with_hyphen = "550e8400-e29b41d4a716446655440000";
+        // This is 33 characters (32 hex + 1 hyphen), which isn't a
+        // recognized length at all.
+        assert_eq!(with_hyphen.len(), 33);
+        let result = encode_uuid(with_hyphen);
+        assert!(matches!(result, Err(B58UUIDError::InvalidLength { .. })));
+    }
+
+    #[test]
+    fn test_encode_uuid_rejects_non_ascii_without_panicking() {
+        // A multi-byte UTF-8 character can make the *byte* length land on
+        // 32 or 36 while the *char* count differs, which would misalign
+        // byte-offset hex slicing and panic on a non-char-boundary index
+        // if non-ASCII input weren't rejected up front.
+        let with_multibyte = format!("0\u{e9}{}", "0".repeat(29));
+        assert_eq!(with_multibyte.len(), 32);
+        let result = encode_uuid(&with_multibyte);
+        assert!(matches!(result, Err(B58UUIDError::InvalidUUID(_))));
+    }
+
+    #[test]
+    fn test_decode_to_uuid_simple_round_trip() {
+        let b58 = encode_uuid("550e8400-e29b-41d4-a716-446655440000").unwrap();
+        let simple = decode_to_uuid_simple(&b58).unwrap();
+        assert_eq!(simple, "550e8400e29b41d4a716446655440000");
+        assert_eq!(encode_uuid(&simple).unwrap(), b58);
+    }
+
     #[test]
     fn test_decode_output_lowercase() {
         // Verify that decode_to_uuid always outputs lowercase
@@ -803,6 +1111,450 @@ mod tests {
             format!("{}", err4),
             "Arithmetic overflow: value exceeds maximum UUID value"
         );
+
+        let err5 = B58UUIDError::BadChecksum {
+            expected: 0xdeadbeef,
+            actual: 0x00000000,
+        };
+        assert_eq!(
+            format!("{}", err5),
+            "Checksum mismatch: expected deadbeef, got 00000000"
+        );
+    }
+
+    #[test]
+    fn test_encode_decode_check_round_trip() {
+        let payload = [
+            0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4, 0xa7, 0x16, 0x44, 0x66, 0x55, 0x44,
+            0x00, 0x00,
+        ];
+        let encoded = encode_check(&payload);
+        let decoded = decode_check(&encoded).unwrap();
+        assert_eq!(decoded, payload);
+    }
+
+    #[test]
+    fn test_decode_check_rejects_typo() {
+        let payload = [0xAAu8; 16];
+        let mut encoded = encode_check(&payload);
+
+        // Flip the last character to simulate a single mistyped character.
+        let last = encoded.pop().unwrap();
+        let replacement = if last == '1' { '2' } else { '1' };
+        encoded.push(replacement);
+
+        let result = decode_check(&encoded);
+        assert!(matches!(result, Err(B58UUIDError::BadChecksum { .. })));
+    }
+
+    #[test]
+    fn test_encode_decode_uuid_check_round_trip() {
+        let uuid_bytes = [0x12u8; 16];
+        let encoded = encode_uuid_check(&uuid_bytes);
+        let decoded = decode_check_to_uuid(&encoded).unwrap();
+        assert_eq!(decoded, uuid_bytes);
+    }
+
+    #[test]
+    fn test_encode_decode_bytes_round_trip() {
+        // encode_bytes/decode_bytes work over arbitrary lengths, not just 16.
+        let cases: Vec<Vec<u8>> = vec![
+            vec![],
+            vec![0x00],
+            vec![0x00, 0x01, 0x02],
+            vec![0xFF; 20], // longer than a UUID, would overflow u128
+            vec![0x00, 0x00, 0x00, 0xFF, 0xFF],
+        ];
+
+        for bytes in cases {
+            let encoded = encode_bytes(&bytes);
+            let decoded = decode_bytes(&encoded).unwrap();
+            assert_eq!(decoded, bytes, "round-trip failed for {:?}", bytes);
+        }
+    }
+
+    #[test]
+    fn test_decode_bytes_no_overflow_restriction() {
+        // 22 'z' characters overflow the fixed-size u128 path, but the
+        // general byte-buffer codec has no such ceiling.
+        let result = decode_bytes("zzzzzzzzzzzzzzzzzzzzzz");
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn test_engine_matches_free_functions() {
+        // The default Bitcoin engine must agree with the crate-root
+        // free functions, since those are thin wrappers around it.
+        let engine = Engine::new(Base58Alphabet::BITCOIN);
+        let uuid_bytes = [
+            0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4, 0xa7, 0x16, 0x44, 0x66, 0x55, 0x44,
+            0x00, 0x00,
+        ];
+        assert_eq!(engine.encode(&uuid_bytes), encode(&uuid_bytes));
+        assert_eq!(engine.decode("BWBeN28Vb7cMEx7Ym8AUzs"), decode("BWBeN28Vb7cMEx7Ym8AUzs"));
+    }
+
+    #[test]
+    fn test_engine_ripple_alphabet_round_trip() {
+        let engine = Engine::new(Base58Alphabet::RIPPLE);
+        let uuid_bytes = [0xAAu8; 16];
+        let encoded = engine.encode(&uuid_bytes);
+        assert_eq!(encoded.len(), 22);
+        assert_eq!(engine.decode(&encoded).unwrap(), uuid_bytes);
+    }
+
+    #[test]
+    fn test_engine_flickr_alphabet_round_trip() {
+        let engine = Engine::new(Base58Alphabet::FLICKR);
+        let uuid_str = "550e8400-e29b-41d4-a716-446655440000";
+        let encoded = engine.encode_uuid(uuid_str).unwrap();
+        assert_eq!(engine.decode_to_uuid(&encoded).unwrap(), uuid_str);
+    }
+
+    #[test]
+    fn test_custom_alphabet_validation() {
+        // Too short.
+        assert!(matches!(
+            Base58Alphabet::new("123"),
+            Err(B58UUIDError::InvalidLength { .. })
+        ));
+
+        // Duplicate character.
+        let duplicate = "1".repeat(58);
+        assert!(matches!(
+            Base58Alphabet::new(&duplicate),
+            Err(B58UUIDError::InvalidBase58(_))
+        ));
+
+        // A valid, independently-built alphabet should work just like a
+        // built-in one.
+        let custom =
+            Base58Alphabet::new("123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz")
+                .unwrap();
+        assert_eq!(custom, Base58Alphabet::BITCOIN);
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_type_round_trip() {
+        let id = uuid::Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
+        let encoded = encode_uuid_type(id);
+        assert_eq!(encoded, "BWBeN28Vb7cMEx7Ym8AUzs");
+        assert_eq!(decode_to_uuid_type(&encoded).unwrap(), id);
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_uuid_support_module_round_trip() {
+        let id = uuid::Uuid::parse_str("550e8400-e29b-41d4-a716-446655440000").unwrap();
+        let encoded = uuid_support::encode(&id);
+        assert_eq!(encoded, "BWBeN28Vb7cMEx7Ym8AUzs");
+        assert_eq!(uuid_support::decode(&encoded).unwrap(), id);
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_new_v4_b58_round_trips_and_is_unique() {
+        let b58_1 = new_v4_b58();
+        let b58_2 = new_v4_b58();
+        assert_ne!(b58_1, b58_2);
+        assert_eq!(b58_1.len(), 22);
+        assert!(decode_to_uuid_type(&b58_1).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "uuid")]
+    fn test_new_v5_b58_is_deterministic() {
+        let b58_1 = new_v5_b58(&uuid::Uuid::NAMESPACE_DNS, b"example.com");
+        let b58_2 = new_v5_b58(&uuid::Uuid::NAMESPACE_DNS, b"example.com");
+        assert_eq!(b58_1, b58_2);
+
+        let different = new_v5_b58(&uuid::Uuid::NAMESPACE_DNS, b"other.example.com");
+        assert_ne!(b58_1, different);
+    }
+
+    #[test]
+    #[cfg(all(feature = "uuid", feature = "std"))]
+    fn test_new_v7_b58_round_trips_and_is_unique() {
+        let b58_1 = new_v7_b58();
+        let b58_2 = new_v7_b58();
+        assert_ne!(b58_1, b58_2);
+        assert_eq!(b58_1.len(), 22);
+        assert!(decode_to_uuid_type(&b58_1).is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_b58uuid_serde_json_round_trip() {
+        let id = B58Uuid::new([0x55u8; 16]);
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, format!("\"{}\"", encode(&id.0)));
+
+        let back: B58Uuid = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, id);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_b58_with_module() {
+        #[derive(serde::Serialize, serde::Deserialize)]
+        struct Record {
+            #[serde(with = "crate::serde_b58")]
+            id: [u8; 16],
+        }
+
+        let record = Record { id: [0xAAu8; 16] };
+        let json = serde_json::to_string(&record).unwrap();
+        assert_eq!(json, format!("{{\"id\":\"{}\"}}", encode(&record.id)));
+
+        let back: Record = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.id, record.id);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_b58_rejects_invalid_base58() {
+        let result: Result<B58Uuid, _> = serde_json::from_str("\"not base58!\"");
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_random_uuid_round_trip() {
+        // Randomized version of test_round_trip, covering far more of the
+        // 16-byte input space than any hand-picked vector.
+        for _ in 0..5_000 {
+            let mut bytes = [0u8; 16];
+            getrandom::getrandom(&mut bytes).unwrap();
+
+            let encoded = encode(&bytes);
+            assert_eq!(
+                decode(&encoded).unwrap(),
+                bytes,
+                "round-trip failed for {:?}",
+                bytes
+            );
+        }
+    }
+
+    #[test]
+    fn test_random_bytes_round_trip() {
+        // Randomized version of test_encode_decode_bytes_round_trip: random
+        // lengths from 0 to 64 bytes, with a random number of leading zero
+        // bytes, to shake out edge cases in the arbitrary-length codec.
+        for _ in 0..2_000 {
+            let mut len_byte = [0u8; 1];
+            getrandom::getrandom(&mut len_byte).unwrap();
+            let len = (len_byte[0] as usize) % 65; // 0..=64
+
+            let mut leading_zero_byte = [0u8; 1];
+            getrandom::getrandom(&mut leading_zero_byte).unwrap();
+            let leading_zeros = if len == 0 {
+                0
+            } else {
+                (leading_zero_byte[0] as usize) % (len + 1)
+            };
+
+            let mut bytes = vec![0u8; len];
+            getrandom::getrandom(&mut bytes[leading_zeros..]).unwrap();
+
+            let encoded = encode_bytes(&bytes);
+            let decoded = decode_bytes(&encoded).unwrap();
+            assert_eq!(
+                decoded, bytes,
+                "round-trip failed for {} leading zeros in {:?}",
+                leading_zeros, bytes
+            );
+        }
+    }
+
+    #[test]
+    fn test_encode_to_slice_matches_encode() {
+        let uuid_bytes = [
+            0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4, 0xa7, 0x16, 0x44, 0x66, 0x55, 0x44,
+            0x00, 0x00,
+        ];
+        let mut buf = [0u8; 22];
+        let len = encode_to_slice(&uuid_bytes, &mut buf).unwrap();
+        assert_eq!(len, 22);
+        assert_eq!(&buf[..len], encode(&uuid_bytes).as_bytes());
+    }
+
+    #[test]
+    fn test_encode_to_slice_rejects_short_buffer() {
+        let uuid_bytes = [0u8; 16];
+        let mut buf = [0u8; 21];
+        let result = encode_to_slice(&uuid_bytes, &mut buf);
+        assert!(matches!(
+            result,
+            Err(B58UUIDError::InvalidLength {
+                expected: 22,
+                got: 21
+            })
+        ));
+    }
+
+    #[test]
+    fn test_encode_to_slice_accepts_larger_buffer() {
+        // Only the first 22 bytes should be written; the rest are untouched.
+        let uuid_bytes = [0xFFu8; 16];
+        let mut buf = [b'?'; 30];
+        let len = encode_to_slice(&uuid_bytes, &mut buf).unwrap();
+        assert_eq!(len, 22);
+        assert_eq!(&buf[..22], encode(&uuid_bytes).as_bytes());
+        assert!(buf[22..].iter().all(|&b| b == b'?'));
+    }
+
+    #[test]
+    fn test_decode_to_bytes_matches_decode() {
+        let mut out = [0u8; 16];
+        decode_to_bytes("BWBeN28Vb7cMEx7Ym8AUzs", &mut out).unwrap();
+        assert_eq!(out, decode("BWBeN28Vb7cMEx7Ym8AUzs").unwrap());
+    }
+
+    #[test]
+    fn test_decode_to_bytes_overflow() {
+        let mut out = [0u8; 16];
+        let result = decode_to_bytes("zzzzzzzzzzzzzzzzzzzzzz", &mut out);
+        assert!(matches!(result, Err(B58UUIDError::Overflow)));
+    }
+
+    #[test]
+    fn test_random_buffer_round_trip() {
+        for _ in 0..2_000 {
+            let mut bytes = [0u8; 16];
+            getrandom::getrandom(&mut bytes).unwrap();
+
+            let mut encoded = [0u8; 22];
+            let len = encode_to_slice(&bytes, &mut encoded).unwrap();
+            assert_eq!(len, 22);
+
+            let encoded_str = core::str::from_utf8(&encoded).unwrap();
+            let mut decoded = [0u8; 16];
+            decode_to_bytes(encoded_str, &mut decoded).unwrap();
+            assert_eq!(decoded, bytes, "round-trip failed for {:?}", bytes);
+        }
+    }
+
+    #[test]
+    fn test_encode_uuid_always_padded() {
+        let uuid_str = "550e8400-e29b-41d4-a716-446655440000";
+        let encoded = encode_uuid(uuid_str).unwrap();
+        assert_eq!(encoded.len(), 22);
+        assert_eq!(decode_to_uuid(&encoded).unwrap(), uuid_str);
+    }
+
+    #[test]
+    fn test_decode_to_uuid_accepts_unpadded_input() {
+        // "1112" is "000...0" x3 followed by one non-zero digit: value 1.
+        assert_eq!(
+            decode_to_uuid("1112").unwrap(),
+            "00000000-0000-0000-0000-000000000001"
+        );
+    }
+
+    #[test]
+    fn test_decode_to_uuid_rejects_overflow() {
+        let result = decode_to_uuid("zzzzzzzzzzzzzzzzzzzzzz");
+        assert!(matches!(result, Err(B58UUIDError::Overflow)));
+    }
+
+    #[test]
+    fn test_encode_uuid_preserves_sort_order() {
+        // Increasing 128-bit values must encode to lexicographically
+        // increasing Base58 strings, since encode_uuid always pads to a
+        // fixed 22 characters with the alphabet's lowest-sorting digit.
+        let uuids = [
+            "00000000-0000-0000-0000-000000000000",
+            "00000000-0000-0000-0000-000000000001",
+            "00000000-0000-0000-0000-0000000000ff",
+            "00000000-0000-0000-0000-000000010000",
+            "0000ffff-ffff-ffff-ffff-ffffffffffff",
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+        ];
+
+        let mut encoded: Vec<String> = uuids.iter().map(|u| encode_uuid(u).unwrap()).collect();
+        let mut sorted = encoded.clone();
+        sorted.sort();
+
+        assert_eq!(encoded, sorted, "padded encodings should already be sorted");
+
+        encoded.reverse();
+        sorted = encoded.clone();
+        sorted.sort();
+        assert_ne!(encoded, sorted, "sanity check: input wasn't trivially sorted either way");
+    }
+
+    #[test]
+    fn test_padded_round_trip() {
+        let uuid_str = "550e8400-e29b-41d4-a716-446655440000";
+        let encoded = encode_uuid_padded(uuid_str).unwrap();
+        assert_eq!(encoded.len(), 22);
+        assert_eq!(decode_padded(&encoded).unwrap(), uuid_str);
+    }
+
+    #[test]
+    fn test_base32_round_trip() {
+        let uuid_bytes = [
+            0x55, 0x0e, 0x84, 0x00, 0xe2, 0x9b, 0x41, 0xd4, 0xa7, 0x16, 0x44, 0x66, 0x55, 0x44,
+            0x00, 0x00,
+        ];
+        let encoded = encode_uuid_base32(&uuid_bytes);
+        assert_eq!(encoded.len(), 26);
+        assert_eq!(decode_base32(&encoded).unwrap(), uuid_bytes);
+    }
+
+    #[test]
+    fn test_base32_all_zeros_and_ones() {
+        let zeros = [0u8; 16];
+        assert_eq!(decode_base32(&encode_uuid_base32(&zeros)).unwrap(), zeros);
+
+        let ones = [0xFFu8; 16];
+        assert_eq!(decode_base32(&encode_uuid_base32(&ones)).unwrap(), ones);
+    }
+
+    #[test]
+    fn test_base32_case_insensitive() {
+        let uuid_bytes = [0xABu8; 16];
+        let encoded = encode_uuid_base32(&uuid_bytes);
+        assert_eq!(decode_base32(&encoded.to_lowercase()).unwrap(), uuid_bytes);
+    }
+
+    #[test]
+    fn test_base32_confusable_normalization() {
+        // 26 zero bytes encode to all '0' symbols; replacing some with the
+        // confusable 'O'/'o' should still decode to the same value.
+        let zeros = [0u8; 16];
+        let encoded = encode_uuid_base32(&zeros);
+        assert_eq!(encoded, "0".repeat(26));
+
+        let with_o = "OOOOOOOOOOOOOOOOOOOOOOOOOo";
+        assert_eq!(decode_base32(with_o).unwrap(), zeros);
+    }
+
+    #[test]
+    fn test_base32_rejects_invalid_character() {
+        // 'U' is deliberately excluded from the Crockford alphabet.
+        let input = format!("U{}", "0".repeat(25));
+        let result = decode_base32(&input);
+        assert!(matches!(result, Err(B58UUIDError::InvalidBase32(_))));
+    }
+
+    #[test]
+    fn test_base32_rejects_wrong_length() {
+        let result = decode_base32("000000");
+        assert!(matches!(
+            result,
+            Err(B58UUIDError::InvalidLength { expected: 26, got: 6 })
+        ));
+    }
+
+    #[test]
+    fn test_base32_rejects_overflow() {
+        // A leading symbol above 0b111 (here 'Z', value 31) would need
+        // more than 3 significant bits, which can't fit in 16 bytes.
+        let input = format!("Z{}", "0".repeat(25));
+        let result = decode_base32(&input);
+        assert!(matches!(result, Err(B58UUIDError::Overflow)));
     }
 
     #[test]
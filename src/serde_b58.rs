@@ -0,0 +1,124 @@
+//! Optional [`serde`] support, enabled by the `serde` feature.
+//!
+//! This module can be used two ways:
+//!
+//! - As a `#[serde(with = "b58uuid::serde_b58")]` annotation on an existing
+//!   `[u8; 16]` (or, under the `uuid` feature, `uuid::Uuid`) field, to
+//!   (de)serialize it as a Base58 string without changing its in-memory
+//!   type.
+//! - Via the [`B58Uuid`] newtype, whose `Serialize`/`Deserialize` impls
+//!   emit the Base58 form for human-readable formats (JSON, TOML, ...) and
+//!   fall back to the compact `[u8; 16]` representation for binary formats
+//!   (bincode, MessagePack, ...), mirroring the `uuid` crate's
+//!   `serde_support`.
+
+use alloc::string::String;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+/// A type whose value is, at its core, 16 raw UUID bytes — implemented for
+/// `[u8; 16]` and, under the `uuid` feature, [`uuid::Uuid`]. This is what
+/// lets [`serialize`]/[`deserialize`] work as a `#[serde(with = ...)]`
+/// module for either field type.
+pub trait AsUuidBytes: Sized {
+    fn as_uuid_bytes(&self) -> &[u8; 16];
+    fn from_uuid_bytes(bytes: [u8; 16]) -> Self;
+}
+
+impl AsUuidBytes for [u8; 16] {
+    fn as_uuid_bytes(&self) -> &[u8; 16] {
+        self
+    }
+
+    fn from_uuid_bytes(bytes: [u8; 16]) -> Self {
+        bytes
+    }
+}
+
+#[cfg(feature = "uuid")]
+impl AsUuidBytes for uuid::Uuid {
+    fn as_uuid_bytes(&self) -> &[u8; 16] {
+        self.as_bytes()
+    }
+
+    fn from_uuid_bytes(bytes: [u8; 16]) -> Self {
+        uuid::Uuid::from_bytes(bytes)
+    }
+}
+
+/// Serializes a `[u8; 16]` (or `Uuid`) field as its Base58 string. For use
+/// via `#[serde(with = "b58uuid::serde_b58")]`.
+pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: AsUuidBytes,
+    S: Serializer,
+{
+    serializer.serialize_str(&crate::encode(value.as_uuid_bytes()))
+}
+
+/// Deserializes a `[u8; 16]` (or `Uuid`) field from its Base58 string. For
+/// use via `#[serde(with = "b58uuid::serde_b58")]`.
+pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+where
+    T: AsUuidBytes,
+    D: Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    crate::decode(&s).map(T::from_uuid_bytes).map_err(de::Error::custom)
+}
+
+/// A 16-byte UUID that (de)serializes as Base58 in human-readable formats
+/// and as a compact `[u8; 16]` in binary formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct B58Uuid(pub [u8; 16]);
+
+impl B58Uuid {
+    /// Wraps a raw 16-byte UUID value.
+    pub const fn new(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+
+    /// Unwraps to the raw 16-byte UUID value.
+    pub const fn into_bytes(self) -> [u8; 16] {
+        self.0
+    }
+}
+
+impl From<[u8; 16]> for B58Uuid {
+    fn from(bytes: [u8; 16]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl From<B58Uuid> for [u8; 16] {
+    fn from(id: B58Uuid) -> Self {
+        id.0
+    }
+}
+
+impl Serialize for B58Uuid {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&crate::encode(&self.0))
+        } else {
+            self.0.serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for B58Uuid {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        if deserializer.is_human_readable() {
+            let s = String::deserialize(deserializer)?;
+            crate::decode(&s).map(B58Uuid).map_err(de::Error::custom)
+        } else {
+            <[u8; 16]>::deserialize(deserializer).map(B58Uuid)
+        }
+    }
+}
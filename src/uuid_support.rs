@@ -0,0 +1,111 @@
+//! Optional integration with the [`uuid`] crate, enabled by the `uuid`
+//! feature.
+//!
+//! These functions convert a [`uuid::Uuid`] to and from Base58 directly via
+//! `Uuid::as_bytes`/`Uuid::from_bytes`, skipping the hyphenated-hex detour
+//! that [`crate::encode_uuid`]/[`crate::decode_to_uuid`] require.
+//!
+//! There is deliberately no `impl From<uuid::Uuid> for String`: both
+//! `From` and `String` are foreign to this crate, so Rust's orphan rules
+//! forbid it. Use [`encode_uuid_type`] instead.
+
+use alloc::string::String;
+
+use crate::{B58UUIDError, Engine};
+
+/// Encodes a [`uuid::Uuid`] directly to Base58.
+///
+/// # Example
+/// ```
+/// use b58uuid::encode_uuid_type;
+/// use uuid::Uuid;
+///
+/// let id = Uuid::nil();
+/// assert_eq!(encode_uuid_type(id), "1111111111111111111111");
+/// ```
+pub fn encode_uuid_type(id: uuid::Uuid) -> String {
+    Engine::DEFAULT.encode(id.as_bytes())
+}
+
+/// Decodes a Base58 string directly to a [`uuid::Uuid`].
+///
+/// # Example
+/// ```
+/// use b58uuid::decode_to_uuid_type;
+///
+/// let id = decode_to_uuid_type("1111111111111111111111").unwrap();
+/// assert!(id.is_nil());
+/// ```
+pub fn decode_to_uuid_type(b58: &str) -> Result<uuid::Uuid, B58UUIDError> {
+    Engine::DEFAULT.decode(b58).map(uuid::Uuid::from_bytes)
+}
+
+/// Encodes a [`uuid::Uuid`] reference directly to Base58.
+///
+/// A by-reference alias of [`encode_uuid_type`]: lives in this module
+/// rather than at the crate root because [`crate::encode`] already takes
+/// that name for the raw `[u8; 16]` API; reach this one as
+/// `b58uuid::uuid_support::encode`.
+pub fn encode(u: &uuid::Uuid) -> String {
+    encode_uuid_type(*u)
+}
+
+/// Decodes a Base58 string directly to a [`uuid::Uuid`].
+///
+/// An alias of [`decode_to_uuid_type`]: lives in this module rather than
+/// at the crate root because [`crate::decode`] already takes that name
+/// for the raw `[u8; 16]` API; reach this one as
+/// `b58uuid::uuid_support::decode`.
+pub fn decode(s: &str) -> Result<uuid::Uuid, B58UUIDError> {
+    decode_to_uuid_type(s)
+}
+
+/// Generates a new random (version 4) UUID and returns its Base58-encoded
+/// representation directly, saving the explicit `Uuid::new_v4()` then
+/// [`encode_uuid_type`] dance.
+///
+/// # Example
+/// ```
+/// use b58uuid::new_v4_b58;
+///
+/// let b58 = new_v4_b58();
+/// assert_eq!(b58.len(), 22);
+/// ```
+pub fn new_v4_b58() -> String {
+    encode_uuid_type(uuid::Uuid::new_v4())
+}
+
+/// Generates a deterministic (version 5, SHA-1 name-based) UUID within
+/// `namespace` and returns its Base58-encoded representation directly.
+///
+/// # Example
+/// ```
+/// use b58uuid::new_v5_b58;
+/// use uuid::Uuid;
+///
+/// let b58_1 = new_v5_b58(&Uuid::NAMESPACE_DNS, b"example.com");
+/// let b58_2 = new_v5_b58(&Uuid::NAMESPACE_DNS, b"example.com");
+/// assert_eq!(b58_1, b58_2); // Deterministic for the same namespace/name
+/// ```
+pub fn new_v5_b58(namespace: &uuid::Uuid, name: &[u8]) -> String {
+    encode_uuid_type(uuid::Uuid::new_v5(namespace, name))
+}
+
+/// Generates a new time-ordered (version 7) UUID and returns its
+/// Base58-encoded representation directly.
+///
+/// Built on [`crate::new_v7_bytes`] rather than `uuid::Uuid::now_v7`, so it
+/// shares that function's wall-clock source and therefore also needs the
+/// `std` feature.
+///
+/// # Example
+/// ```
+/// use b58uuid::new_v7_b58;
+///
+/// let b58 = new_v7_b58();
+/// assert_eq!(b58.len(), 22);
+/// ```
+#[cfg(feature = "std")]
+pub fn new_v7_b58() -> String {
+    encode_uuid_type(uuid::Uuid::from_bytes(crate::new_v7_bytes()))
+}